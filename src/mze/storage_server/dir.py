@@ -168,17 +168,25 @@ async def handle_put(rctx: RequestContext) -> web.StreamResponse:
                               {str(record_uuid): [str(version_uuid)]}})
 
 
-async def handle_get(rctx: RequestContext) -> web.StreamResponse:
+def version_path(rctx: RequestContext) -> Path:
+    """
+    Returns the path to the requested version of the record. The latest
+    version is used if version is not specified.
+    """
     # TODO check instance
     if 'record' not in rctx.params:
         raise web.HTTPBadRequest(
-            reason='record UUID is required for get requests')
+            reason=f'record UUID is required for {rctx.op} requests')
     else:
         record_dir = rctx.storage_dir / rctx.params['record']
     if not record_dir.exists():
         raise web.HTTPNotFound(
             reason=f'record {rctx.params["record"]} doesn\'t exist.')
     av = all_versions(record_dir)
+    # the record dir is created before the first version is written
+    if len(av) == 0:
+        raise web.HTTPNotFound(
+            reason=f'record {rctx.params["record"]} doesn\'t have versions.')
     if 'version' in rctx.params:
         version_uuid = rctx.params["version"]
         if version_uuid not in av.values():
@@ -191,8 +199,20 @@ async def handle_get(rctx: RequestContext) -> web.StreamResponse:
     else:
         version_number = max(av.keys())
         version_uuid = av[version_number]
-    return web.FileResponse(
-        path=record_dir / f'{version_number}-{version_uuid}')
+    return record_dir / f'{version_number}-{version_uuid}'
+
+
+async def handle_get(rctx: RequestContext) -> web.StreamResponse:
+    return web.FileResponse(path=version_path(rctx))
+
+
+async def handle_head(rctx: RequestContext) -> web.StreamResponse:
+    """
+    Same response as for get. FileResponse doesn't send the body for HEAD
+    requests, so Content-Length, Content-Type, ETag and Last-Modified are
+    always the same as for get.
+    """
+    return await handle_get(rctx)
 
 
 async def handle_not_implemented_yet(rctx: RequestContext) -> \
@@ -209,7 +229,7 @@ class OpHandler:
 OPERATIONS: dict[str, OpHandler] = {
     'get': OpHandler(web.get, handle_get),
     'put': OpHandler(web.put, handle_put),
-    'head': OpHandler(web.head, handle_not_implemented_yet),
+    'head': OpHandler(web.head, handle_head),
     'list': OpHandler(web.get, handle_list),
     'delete': OpHandler(web.delete, handle_not_implemented_yet),
 }